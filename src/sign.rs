@@ -6,13 +6,15 @@ use core::marker::PhantomData;
 use self::ecdsa::EcdsaSigningKeyP256;
 #[cfg(feature = "p384")]
 use self::ecdsa::EcdsaSigningKeyP384;
+#[cfg(feature = "p521")]
+use self::ecdsa::EcdsaSigningKeyP521;
 #[cfg(feature = "x25519")]
 use self::eddsa::Ed25519SigningKey;
 use self::rsa::RsaSigningKey;
 
 use pki_types::PrivateKeyDer;
 use rustls::sign::{Signer, SigningKey};
-use rustls::{Error, SignatureScheme};
+use rustls::{Error, SignatureAlgorithm, SignatureScheme};
 use signature::{RandomizedSigner, SignatureEncoding};
 
 #[derive(Debug)]
@@ -71,6 +73,131 @@ where
     }
 }
 
+/// A rustls [`SigningKey`] that wraps a caller-supplied, deterministic
+/// [`signature::Signer`] under a fixed [`SignatureScheme`].
+///
+/// This lets keys that live outside this crate's DER parsing — for example
+/// a PKCS#11 token or a remote KMS signer — plug into a rustls
+/// [`rustls::sign::CertifiedKey`] as long as they implement the RustCrypto
+/// `Signer` trait over their own signature type.
+#[derive(Debug)]
+pub struct CustomSigningKey<S, T>
+where
+    S: SignatureEncoding,
+    T: signature::Signer<S>,
+{
+    inner: GenericSigner<S, T>,
+    algorithm: SignatureAlgorithm,
+}
+
+impl<S, T> CustomSigningKey<S, T>
+where
+    S: SignatureEncoding + Send + Sync + core::fmt::Debug + 'static,
+    T: signature::Signer<S> + Send + Sync + core::fmt::Debug + 'static,
+{
+    /// Wrap `signer` as a rustls [`SigningKey`] that only ever negotiates
+    /// `scheme`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        signer: Arc<T>,
+        scheme: SignatureScheme,
+        algorithm: SignatureAlgorithm,
+    ) -> Arc<dyn SigningKey> {
+        Arc::new(Self {
+            inner: GenericSigner {
+                _marker: PhantomData,
+                key: signer,
+                scheme,
+            },
+            algorithm,
+        })
+    }
+}
+
+impl<S, T> SigningKey for CustomSigningKey<S, T>
+where
+    S: SignatureEncoding + Send + Sync + core::fmt::Debug + 'static,
+    T: signature::Signer<S> + Send + Sync + core::fmt::Debug + 'static,
+{
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if offered.contains(&self.inner.scheme) {
+            Some(Box::new(GenericSigner {
+                _marker: PhantomData,
+                key: self.inner.key.clone(),
+                scheme: self.inner.scheme,
+            }))
+        } else {
+            None
+        }
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        self.algorithm
+    }
+}
+
+/// A rustls [`SigningKey`] that wraps a caller-supplied
+/// [`signature::RandomizedSigner`] under a fixed [`SignatureScheme`].
+///
+/// See [`CustomSigningKey`] for the deterministic-signer counterpart; use
+/// this one for signers (such as RSA-PSS) that require fresh randomness on
+/// every signature.
+#[derive(Debug)]
+pub struct CustomRandomizedSigningKey<S, T>
+where
+    S: SignatureEncoding,
+    T: RandomizedSigner<S>,
+{
+    inner: GenericRandomizedSigner<S, T>,
+    algorithm: SignatureAlgorithm,
+}
+
+impl<S, T> CustomRandomizedSigningKey<S, T>
+where
+    S: SignatureEncoding + Send + Sync + core::fmt::Debug + 'static,
+    T: RandomizedSigner<S> + Send + Sync + core::fmt::Debug + 'static,
+{
+    /// Wrap `signer` as a rustls [`SigningKey`] that only ever negotiates
+    /// `scheme`.
+    #[allow(clippy::new_ret_no_self)]
+    pub fn new(
+        signer: Arc<T>,
+        scheme: SignatureScheme,
+        algorithm: SignatureAlgorithm,
+    ) -> Arc<dyn SigningKey> {
+        Arc::new(Self {
+            inner: GenericRandomizedSigner {
+                _marker: PhantomData,
+                key: signer,
+                scheme,
+            },
+            algorithm,
+        })
+    }
+}
+
+impl<S, T> SigningKey for CustomRandomizedSigningKey<S, T>
+where
+    S: SignatureEncoding + Send + Sync + core::fmt::Debug + 'static,
+    T: RandomizedSigner<S> + Send + Sync + core::fmt::Debug + 'static,
+{
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if offered.contains(&self.inner.scheme) {
+            Some(Box::new(GenericRandomizedSigner {
+                _marker: PhantomData,
+                key: self.inner.key.clone(),
+                scheme: self.inner.scheme,
+            }))
+        } else {
+            None
+        }
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        self.algorithm
+    }
+}
+
 /// Extract any supported key from the given DER input.
 ///
 /// # Errors
@@ -104,6 +231,11 @@ pub fn any_ecdsa_type(
         result = result.or_else(|_| EcdsaSigningKeyP384::try_from(der).map(|x| Arc::new(x) as _));
     }
 
+    #[cfg(feature = "p521")]
+    {
+        result = result.or_else(|_| EcdsaSigningKeyP521::try_from(der).map(|x| Arc::new(x) as _));
+    }
+
     result
 }
 
@@ -131,3 +263,93 @@ pub fn any_eddsa_type(
 pub mod ecdsa;
 pub mod eddsa;
 pub mod rsa;
+
+// Covers the CustomSigningKey/CustomRandomizedSigningKey adapters
+// (dgherzka/rustls-rustcrypto#chunk0-4).
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug)]
+    struct DummySignature([u8; 4]);
+
+    impl signature::SignatureEncoding for DummySignature {
+        type Repr = [u8; 4];
+    }
+
+    impl TryFrom<&[u8]> for DummySignature {
+        type Error = signature::Error;
+
+        fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+            bytes
+                .try_into()
+                .map(DummySignature)
+                .map_err(|_| signature::Error::new())
+        }
+    }
+
+    impl From<DummySignature> for [u8; 4] {
+        fn from(sig: DummySignature) -> Self {
+            sig.0
+        }
+    }
+
+    #[derive(Debug)]
+    struct DummySigner;
+
+    impl signature::Signer<DummySignature> for DummySigner {
+        fn try_sign(&self, _msg: &[u8]) -> Result<DummySignature, signature::Error> {
+            Ok(DummySignature(*b"sig!"))
+        }
+    }
+
+    impl signature::RandomizedSigner<DummySignature> for DummySigner {
+        fn try_sign_with_rng(
+            &self,
+            _rng: &mut impl signature::rand_core::CryptoRngCore,
+            _msg: &[u8],
+        ) -> Result<DummySignature, signature::Error> {
+            Ok(DummySignature(*b"sig!"))
+        }
+    }
+
+    #[test]
+    fn custom_signing_key_only_negotiates_its_fixed_scheme() {
+        let key = CustomSigningKey::new(
+            Arc::new(DummySigner),
+            SignatureScheme::ED25519,
+            SignatureAlgorithm::ED25519,
+        );
+
+        assert_eq!(key.algorithm(), SignatureAlgorithm::ED25519);
+        assert!(key
+            .choose_scheme(&[SignatureScheme::ECDSA_NISTP256_SHA256])
+            .is_none());
+
+        let signer = key
+            .choose_scheme(&[SignatureScheme::ED25519])
+            .expect("fixed scheme offered");
+        assert_eq!(signer.scheme(), SignatureScheme::ED25519);
+        assert_eq!(signer.sign(b"message").unwrap(), b"sig!");
+    }
+
+    #[test]
+    fn custom_randomized_signing_key_only_negotiates_its_fixed_scheme() {
+        let key = CustomRandomizedSigningKey::new(
+            Arc::new(DummySigner),
+            SignatureScheme::RSA_PSS_SHA256,
+            SignatureAlgorithm::RSA,
+        );
+
+        assert_eq!(key.algorithm(), SignatureAlgorithm::RSA);
+        assert!(key
+            .choose_scheme(&[SignatureScheme::ED25519])
+            .is_none());
+
+        let signer = key
+            .choose_scheme(&[SignatureScheme::RSA_PSS_SHA256])
+            .expect("fixed scheme offered");
+        assert_eq!(signer.scheme(), SignatureScheme::RSA_PSS_SHA256);
+        assert_eq!(signer.sign(b"message").unwrap(), b"sig!");
+    }
+}
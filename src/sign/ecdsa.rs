@@ -0,0 +1,434 @@
+//! ECDSA signing keys backed by RustCrypto's `p256`/`p384`/`p521` crates.
+
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+
+use pki_types::PrivateKeyDer;
+use rustls::sign::{Signer, SigningKey};
+use rustls::{Error, SignatureAlgorithm, SignatureScheme};
+
+use crate::sign::{GenericRandomizedSigner, GenericSigner};
+
+#[cfg(feature = "p256")]
+type P256Signature = ecdsa::der::Signature<p256::NistP256>;
+
+#[cfg(feature = "p256")]
+/// A rustls [`SigningKey`] backed by a P-256 key.
+///
+/// Signing is deterministic (RFC 6979) by default. Construct with
+/// [`EcdsaSigningKeyP256::with_hedged_signing`] to additionally mix in
+/// fresh entropy from [`rand_core::OsRng`] on every signature.
+#[derive(Debug)]
+pub struct EcdsaSigningKeyP256 {
+    key: Arc<p256::ecdsa::SigningKey>,
+    scheme: SignatureScheme,
+    hedged: bool,
+}
+
+#[cfg(feature = "p256")]
+impl TryFrom<&PrivateKeyDer<'_>> for EcdsaSigningKeyP256 {
+    type Error = Error;
+
+    fn try_from(value: &PrivateKeyDer<'_>) -> Result<Self, Self::Error> {
+        let key = match value {
+            PrivateKeyDer::Pkcs8(der) => {
+                use p256::pkcs8::DecodePrivateKey;
+                p256::ecdsa::SigningKey::from_pkcs8_der(der.secret_pkcs8_der())
+                    .map_err(|_| Error::General("invalid P-256 private key".into()))?
+            }
+            PrivateKeyDer::Sec1(der) => {
+                let secret_key = p256::SecretKey::from_sec1_der(der.secret_sec1_der())
+                    .map_err(|_| Error::General("invalid P-256 private key".into()))?;
+                p256::ecdsa::SigningKey::from(secret_key)
+            }
+            _ => return Err(Error::General("unsupported key encoding for P-256".into())),
+        };
+
+        Ok(Self {
+            key: Arc::new(key),
+            scheme: SignatureScheme::ECDSA_NISTP256_SHA256,
+            hedged: false,
+        })
+    }
+}
+
+#[cfg(feature = "p256")]
+impl EcdsaSigningKeyP256 {
+    /// Opt into hedged (deterministic + `OsRng`-mixed) nonce generation
+    /// instead of plain RFC 6979 determinism.
+    #[must_use]
+    pub fn with_hedged_signing(mut self) -> Self {
+        self.hedged = true;
+        self
+    }
+}
+
+#[cfg(feature = "p256")]
+impl SigningKey for EcdsaSigningKeyP256 {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if !offered.contains(&self.scheme) {
+            return None;
+        }
+
+        if self.hedged {
+            Some(Box::new(GenericRandomizedSigner::<P256Signature, _> {
+                _marker: PhantomData,
+                key: self.key.clone(),
+                scheme: self.scheme,
+            }))
+        } else {
+            Some(Box::new(GenericSigner::<P256Signature, _> {
+                _marker: PhantomData,
+                key: self.key.clone(),
+                scheme: self.scheme,
+            }))
+        }
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::ECDSA
+    }
+}
+
+#[cfg(feature = "p384")]
+type P384Signature = ecdsa::der::Signature<p384::NistP384>;
+
+#[cfg(feature = "p384")]
+/// A rustls [`SigningKey`] backed by a P-384 key.
+///
+/// Signing is deterministic (RFC 6979) by default. Construct with
+/// [`EcdsaSigningKeyP384::with_hedged_signing`] to additionally mix in
+/// fresh entropy from [`rand_core::OsRng`] on every signature.
+#[derive(Debug)]
+pub struct EcdsaSigningKeyP384 {
+    key: Arc<p384::ecdsa::SigningKey>,
+    scheme: SignatureScheme,
+    hedged: bool,
+}
+
+#[cfg(feature = "p384")]
+impl TryFrom<&PrivateKeyDer<'_>> for EcdsaSigningKeyP384 {
+    type Error = Error;
+
+    fn try_from(value: &PrivateKeyDer<'_>) -> Result<Self, Self::Error> {
+        let key = match value {
+            PrivateKeyDer::Pkcs8(der) => {
+                use p384::pkcs8::DecodePrivateKey;
+                p384::ecdsa::SigningKey::from_pkcs8_der(der.secret_pkcs8_der())
+                    .map_err(|_| Error::General("invalid P-384 private key".into()))?
+            }
+            PrivateKeyDer::Sec1(der) => {
+                let secret_key = p384::SecretKey::from_sec1_der(der.secret_sec1_der())
+                    .map_err(|_| Error::General("invalid P-384 private key".into()))?;
+                p384::ecdsa::SigningKey::from(secret_key)
+            }
+            _ => return Err(Error::General("unsupported key encoding for P-384".into())),
+        };
+
+        Ok(Self {
+            key: Arc::new(key),
+            scheme: SignatureScheme::ECDSA_NISTP384_SHA384,
+            hedged: false,
+        })
+    }
+}
+
+#[cfg(feature = "p384")]
+impl EcdsaSigningKeyP384 {
+    /// Opt into hedged (deterministic + `OsRng`-mixed) nonce generation
+    /// instead of plain RFC 6979 determinism.
+    #[must_use]
+    pub fn with_hedged_signing(mut self) -> Self {
+        self.hedged = true;
+        self
+    }
+}
+
+#[cfg(feature = "p384")]
+impl SigningKey for EcdsaSigningKeyP384 {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if !offered.contains(&self.scheme) {
+            return None;
+        }
+
+        if self.hedged {
+            Some(Box::new(GenericRandomizedSigner::<P384Signature, _> {
+                _marker: PhantomData,
+                key: self.key.clone(),
+                scheme: self.scheme,
+            }))
+        } else {
+            Some(Box::new(GenericSigner::<P384Signature, _> {
+                _marker: PhantomData,
+                key: self.key.clone(),
+                scheme: self.scheme,
+            }))
+        }
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::ECDSA
+    }
+}
+
+#[cfg(feature = "p521")]
+type P521Signature = ecdsa::der::Signature<p521::NistP521>;
+
+#[cfg(feature = "p521")]
+/// Wraps [`p521::ecdsa::SigningKey`] to give it the `Debug` impl that
+/// [`GenericSigner`]/[`GenericRandomizedSigner`] require; unlike
+/// `p256`/`p384`, `p521`'s signing key only derives `Clone`.
+struct P521Key(p521::ecdsa::SigningKey);
+
+#[cfg(feature = "p521")]
+impl core::fmt::Debug for P521Key {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("P521Key").finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "p521")]
+impl signature::Signer<P521Signature> for P521Key {
+    fn try_sign(&self, msg: &[u8]) -> Result<P521Signature, signature::Error> {
+        signature::Signer::<p521::ecdsa::Signature>::try_sign(&self.0, msg).map(|sig| sig.to_der())
+    }
+}
+
+#[cfg(feature = "p521")]
+impl signature::RandomizedSigner<P521Signature> for P521Key {
+    fn try_sign_with_rng(
+        &self,
+        rng: &mut impl signature::rand_core::CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<P521Signature, signature::Error> {
+        signature::RandomizedSigner::<p521::ecdsa::Signature>::try_sign_with_rng(&self.0, rng, msg)
+            .map(|sig| sig.to_der())
+    }
+}
+
+#[cfg(feature = "p521")]
+/// A rustls [`SigningKey`] backed by a P-521 key.
+///
+/// Signing is deterministic (RFC 6979) by default. Construct with
+/// [`EcdsaSigningKeyP521::with_hedged_signing`] to additionally mix in
+/// fresh entropy from [`rand_core::OsRng`] on every signature.
+#[derive(Debug)]
+pub struct EcdsaSigningKeyP521 {
+    key: Arc<P521Key>,
+    scheme: SignatureScheme,
+    hedged: bool,
+}
+
+#[cfg(feature = "p521")]
+impl TryFrom<&PrivateKeyDer<'_>> for EcdsaSigningKeyP521 {
+    type Error = Error;
+
+    fn try_from(value: &PrivateKeyDer<'_>) -> Result<Self, Self::Error> {
+        let secret_key = match value {
+            PrivateKeyDer::Pkcs8(der) => {
+                use p521::pkcs8::DecodePrivateKey;
+                p521::SecretKey::from_pkcs8_der(der.secret_pkcs8_der())
+                    .map_err(|_| Error::General("invalid P-521 private key".into()))?
+            }
+            PrivateKeyDer::Sec1(der) => p521::SecretKey::from_sec1_der(der.secret_sec1_der())
+                .map_err(|_| Error::General("invalid P-521 private key".into()))?,
+            _ => return Err(Error::General("unsupported key encoding for P-521".into())),
+        };
+        let key =
+            p521::ecdsa::SigningKey::from(ecdsa::SigningKey::<p521::NistP521>::from(secret_key));
+
+        Ok(Self {
+            key: Arc::new(P521Key(key)),
+            scheme: SignatureScheme::ECDSA_NISTP521_SHA512,
+            hedged: false,
+        })
+    }
+}
+
+#[cfg(feature = "p521")]
+impl EcdsaSigningKeyP521 {
+    /// Opt into hedged (deterministic + `OsRng`-mixed) nonce generation
+    /// instead of plain RFC 6979 determinism.
+    #[must_use]
+    pub fn with_hedged_signing(mut self) -> Self {
+        self.hedged = true;
+        self
+    }
+}
+
+#[cfg(feature = "p521")]
+impl SigningKey for EcdsaSigningKeyP521 {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if !offered.contains(&self.scheme) {
+            return None;
+        }
+
+        if self.hedged {
+            Some(Box::new(GenericRandomizedSigner::<P521Signature, _> {
+                _marker: PhantomData,
+                key: self.key.clone(),
+                scheme: self.scheme,
+            }))
+        } else {
+            Some(Box::new(GenericSigner::<P521Signature, _> {
+                _marker: PhantomData,
+                key: self.key.clone(),
+                scheme: self.scheme,
+            }))
+        }
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::ECDSA
+    }
+}
+
+#[cfg(all(test, feature = "p256"))]
+mod p256_tests {
+    use super::*;
+    use p256::elliptic_curve::pkcs8::EncodePrivateKey;
+    use pki_types::{PrivatePkcs8KeyDer, PrivateSec1KeyDer};
+    use signature::Verifier;
+
+    fn generate() -> p256::SecretKey {
+        p256::SecretKey::random(&mut rand_core::OsRng)
+    }
+
+    #[test]
+    fn round_trips_pkcs8_and_verifies() {
+        let secret_key = generate();
+        let signing_key = p256::ecdsa::SigningKey::from(&secret_key);
+        let der = secret_key.to_pkcs8_der().unwrap();
+        let private = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.as_bytes().to_vec()));
+
+        let key = EcdsaSigningKeyP256::try_from(&private).unwrap();
+        let signer = key
+            .choose_scheme(&[SignatureScheme::ECDSA_NISTP256_SHA256])
+            .expect("scheme offered");
+
+        let message = b"rustls-rustcrypto";
+        let raw_signature = signer.sign(message).unwrap();
+        let signature = P256Signature::try_from(raw_signature.as_slice()).unwrap();
+        signing_key
+            .verifying_key()
+            .verify(message, &signature)
+            .unwrap();
+    }
+
+    // Covers SEC1 `EC PRIVATE KEY` parsing (dgherzka/rustls-rustcrypto#chunk0-2).
+    #[test]
+    fn round_trips_sec1() {
+        let secret_key = generate();
+        let der = secret_key.to_sec1_der().unwrap();
+        let private = PrivateKeyDer::Sec1(PrivateSec1KeyDer::from(der.to_vec()));
+
+        assert!(EcdsaSigningKeyP256::try_from(&private).is_ok());
+    }
+
+    #[test]
+    fn rejects_scheme_not_offered() {
+        let secret_key = generate();
+        let der = secret_key.to_pkcs8_der().unwrap();
+        let private = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.as_bytes().to_vec()));
+        let key = EcdsaSigningKeyP256::try_from(&private).unwrap();
+
+        assert!(key.choose_scheme(&[SignatureScheme::ED25519]).is_none());
+    }
+
+    // Covers hedged/randomized nonce generation (dgherzka/rustls-rustcrypto#chunk0-5).
+    #[test]
+    fn hedged_signature_still_verifies() {
+        let secret_key = generate();
+        let signing_key = p256::ecdsa::SigningKey::from(&secret_key);
+        let der = secret_key.to_pkcs8_der().unwrap();
+        let private = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.as_bytes().to_vec()));
+        let key = EcdsaSigningKeyP256::try_from(&private)
+            .unwrap()
+            .with_hedged_signing();
+
+        let signer = key
+            .choose_scheme(&[SignatureScheme::ECDSA_NISTP256_SHA256])
+            .expect("scheme offered");
+
+        let message = b"hedged nonce";
+        let raw_signature = signer.sign(message).unwrap();
+        let signature = P256Signature::try_from(raw_signature.as_slice()).unwrap();
+        signing_key
+            .verifying_key()
+            .verify(message, &signature)
+            .unwrap();
+    }
+}
+
+#[cfg(all(test, feature = "p384"))]
+mod p384_tests {
+    use super::*;
+    use p384::elliptic_curve::pkcs8::EncodePrivateKey;
+    use pki_types::PrivatePkcs8KeyDer;
+    use signature::Verifier;
+
+    #[test]
+    fn round_trips_pkcs8_and_verifies() {
+        let secret_key = p384::SecretKey::random(&mut rand_core::OsRng);
+        let signing_key = p384::ecdsa::SigningKey::from(&secret_key);
+        let der = secret_key.to_pkcs8_der().unwrap();
+        let private = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.as_bytes().to_vec()));
+
+        let key = EcdsaSigningKeyP384::try_from(&private).unwrap();
+        let signer = key
+            .choose_scheme(&[SignatureScheme::ECDSA_NISTP384_SHA384])
+            .expect("scheme offered");
+
+        let message = b"rustls-rustcrypto";
+        let raw_signature = signer.sign(message).unwrap();
+        let signature = P384Signature::try_from(raw_signature.as_slice()).unwrap();
+        signing_key
+            .verifying_key()
+            .verify(message, &signature)
+            .unwrap();
+    }
+}
+
+#[cfg(all(test, feature = "p521"))]
+mod p521_tests {
+    use super::*;
+    use p521::elliptic_curve::pkcs8::EncodePrivateKey;
+    use pki_types::PrivatePkcs8KeyDer;
+    use signature::Verifier;
+
+    #[test]
+    fn round_trips_pkcs8_and_verifies() {
+        let secret_key = p521::SecretKey::random(&mut rand_core::OsRng);
+        let signing_key = p521::ecdsa::SigningKey::from(ecdsa::SigningKey::<p521::NistP521>::from(
+            &secret_key,
+        ));
+        let verifying_key = p521::ecdsa::VerifyingKey::from(&signing_key);
+        let der = secret_key.to_pkcs8_der().unwrap();
+        let private = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.as_bytes().to_vec()));
+
+        let key = EcdsaSigningKeyP521::try_from(&private).unwrap();
+        let signer = key
+            .choose_scheme(&[SignatureScheme::ECDSA_NISTP521_SHA512])
+            .expect("scheme offered");
+
+        let message = b"rustls-rustcrypto";
+        let raw_signature = signer.sign(message).unwrap();
+        // `p521::ecdsa::VerifyingKey` only implements `Verifier` for the
+        // fixed-size `Signature`, not the DER encoding this crate produces
+        // on the wire, so convert back before verifying.
+        let der_signature = P521Signature::try_from(raw_signature.as_slice()).unwrap();
+        let signature = p521::ecdsa::Signature::try_from(der_signature).unwrap();
+        verifying_key.verify(message, &signature).unwrap();
+    }
+
+    #[test]
+    fn rejects_scheme_not_offered() {
+        let secret_key = p521::SecretKey::random(&mut rand_core::OsRng);
+        let der = secret_key.to_pkcs8_der().unwrap();
+        let private = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.as_bytes().to_vec()));
+        let key = EcdsaSigningKeyP521::try_from(&private).unwrap();
+
+        assert!(key.choose_scheme(&[SignatureScheme::ED25519]).is_none());
+    }
+}
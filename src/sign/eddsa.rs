@@ -0,0 +1,56 @@
+//! EdDSA signing keys backed by RustCrypto's `ed25519-dalek` crate.
+
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+
+use ed25519_dalek::pkcs8::DecodePrivateKey;
+use pki_types::PrivateKeyDer;
+use rustls::sign::{Signer, SigningKey};
+use rustls::{Error, SignatureAlgorithm, SignatureScheme};
+
+use crate::sign::GenericSigner;
+
+/// A rustls [`SigningKey`] backed by an Ed25519 key.
+#[derive(Debug)]
+pub struct Ed25519SigningKey {
+    key: Arc<ed25519_dalek::SigningKey>,
+    scheme: SignatureScheme,
+}
+
+impl TryFrom<&PrivateKeyDer<'_>> for Ed25519SigningKey {
+    type Error = Error;
+
+    fn try_from(value: &PrivateKeyDer<'_>) -> Result<Self, Self::Error> {
+        let key = match value {
+            PrivateKeyDer::Pkcs8(der) => {
+                ed25519_dalek::SigningKey::from_pkcs8_der(der.secret_pkcs8_der())
+                    .map_err(|_| Error::General("invalid Ed25519 private key".into()))?
+            }
+            _ => return Err(Error::General("unsupported key encoding for Ed25519".into())),
+        };
+
+        Ok(Self {
+            key: Arc::new(key),
+            scheme: SignatureScheme::ED25519,
+        })
+    }
+}
+
+impl SigningKey for Ed25519SigningKey {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        if offered.contains(&self.scheme) {
+            Some(Box::new(GenericSigner {
+                _marker: PhantomData,
+                key: self.key.clone(),
+                scheme: self.scheme,
+            }))
+        } else {
+            None
+        }
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::ED25519
+    }
+}
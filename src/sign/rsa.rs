@@ -0,0 +1,176 @@
+//! RSA signing keys backed by RustCrypto's `rsa` crate.
+
+#[cfg(feature = "alloc")]
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+
+use pki_types::PrivateKeyDer;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::traits::PublicKeyParts;
+use rsa::RsaPrivateKey;
+use rustls::sign::{Signer, SigningKey};
+use rustls::{Error, SignatureAlgorithm, SignatureScheme};
+use sha2::{Sha256, Sha384, Sha512};
+
+use crate::sign::{GenericRandomizedSigner, GenericSigner};
+
+/// Minimum RSA modulus size, in bits, accepted from a private key.
+///
+/// Smaller moduli no longer offer an adequate security margin.
+const MIN_MODULUS_BITS: usize = 2048;
+
+/// Maximum RSA modulus size, in bits, accepted from a private key.
+///
+/// Guards against pathologically large keys being used to exhaust CPU
+/// time during signing.
+const MAX_MODULUS_BITS: usize = 8192;
+
+/// The schemes this key can negotiate, in descending preference order.
+///
+/// RSA-PSS is preferred over PKCS#1 v1.5 at each hash strength.
+const SCHEMES: &[SignatureScheme] = &[
+    SignatureScheme::RSA_PSS_SHA512,
+    SignatureScheme::RSA_PSS_SHA384,
+    SignatureScheme::RSA_PSS_SHA256,
+    SignatureScheme::RSA_PKCS1_SHA512,
+    SignatureScheme::RSA_PKCS1_SHA384,
+    SignatureScheme::RSA_PKCS1_SHA256,
+];
+
+/// A rustls [`SigningKey`] backed by an RSA key.
+#[derive(Debug)]
+pub struct RsaSigningKey {
+    key: Arc<RsaPrivateKey>,
+}
+
+impl TryFrom<&PrivateKeyDer<'_>> for RsaSigningKey {
+    type Error = Error;
+
+    fn try_from(value: &PrivateKeyDer<'_>) -> Result<Self, Self::Error> {
+        let key = match value {
+            PrivateKeyDer::Pkcs1(der) => RsaPrivateKey::from_pkcs1_der(der.secret_pkcs1_der())
+                .map_err(|_| Error::General("invalid RSA private key".into()))?,
+            PrivateKeyDer::Pkcs8(der) => RsaPrivateKey::from_pkcs8_der(der.secret_pkcs8_der())
+                .map_err(|_| Error::General("invalid RSA private key".into()))?,
+            _ => return Err(Error::General("unsupported key encoding for RSA".into())),
+        };
+
+        check_modulus_bits(key.n().bits())?;
+
+        Ok(Self { key: Arc::new(key) })
+    }
+}
+
+/// Reject moduli outside `[MIN_MODULUS_BITS, MAX_MODULUS_BITS]`.
+fn check_modulus_bits(bits: usize) -> Result<(), Error> {
+    if bits < MIN_MODULUS_BITS {
+        return Err(Error::General("RSA modulus too small".into()));
+    }
+    if bits > MAX_MODULUS_BITS {
+        return Err(Error::General("RSA modulus too large".into()));
+    }
+    Ok(())
+}
+
+impl SigningKey for RsaSigningKey {
+    fn choose_scheme(&self, offered: &[SignatureScheme]) -> Option<Box<dyn Signer>> {
+        let scheme = *SCHEMES.iter().find(|scheme| offered.contains(scheme))?;
+
+        match scheme {
+            SignatureScheme::RSA_PSS_SHA512 => Some(Box::new(GenericRandomizedSigner {
+                _marker: PhantomData,
+                key: Arc::new(rsa::pss::SigningKey::<Sha512>::new((*self.key).clone())),
+                scheme,
+            })),
+            SignatureScheme::RSA_PSS_SHA384 => Some(Box::new(GenericRandomizedSigner {
+                _marker: PhantomData,
+                key: Arc::new(rsa::pss::SigningKey::<Sha384>::new((*self.key).clone())),
+                scheme,
+            })),
+            SignatureScheme::RSA_PSS_SHA256 => Some(Box::new(GenericRandomizedSigner {
+                _marker: PhantomData,
+                key: Arc::new(rsa::pss::SigningKey::<Sha256>::new((*self.key).clone())),
+                scheme,
+            })),
+            SignatureScheme::RSA_PKCS1_SHA512 => Some(Box::new(GenericSigner {
+                _marker: PhantomData,
+                key: Arc::new(rsa::pkcs1v15::SigningKey::<Sha512>::new((*self.key).clone())),
+                scheme,
+            })),
+            SignatureScheme::RSA_PKCS1_SHA384 => Some(Box::new(GenericSigner {
+                _marker: PhantomData,
+                key: Arc::new(rsa::pkcs1v15::SigningKey::<Sha384>::new((*self.key).clone())),
+                scheme,
+            })),
+            SignatureScheme::RSA_PKCS1_SHA256 => Some(Box::new(GenericSigner {
+                _marker: PhantomData,
+                key: Arc::new(rsa::pkcs1v15::SigningKey::<Sha256>::new((*self.key).clone())),
+                scheme,
+            })),
+            _ => None,
+        }
+    }
+
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::RSA
+    }
+}
+
+// Covers RSA-PSS scheme negotiation and the modulus security gate
+// (dgherzka/rustls-rustcrypto#chunk0-3).
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rsa::pkcs8::EncodePrivateKey;
+    use pki_types::PrivatePkcs8KeyDer;
+
+    #[test]
+    fn rejects_modulus_below_floor() {
+        assert!(check_modulus_bits(MIN_MODULUS_BITS - 1).is_err());
+        assert!(check_modulus_bits(MIN_MODULUS_BITS).is_ok());
+    }
+
+    #[test]
+    fn rejects_modulus_above_cap() {
+        assert!(check_modulus_bits(MAX_MODULUS_BITS).is_ok());
+        assert!(check_modulus_bits(MAX_MODULUS_BITS + 1).is_err());
+    }
+
+    #[test]
+    fn try_from_rejects_weak_key() {
+        let key = RsaPrivateKey::new(&mut rand_core::OsRng, 1024).unwrap();
+        let der = key.to_pkcs8_der().unwrap();
+        let private = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.as_bytes().to_vec()));
+
+        assert!(RsaSigningKey::try_from(&private).is_err());
+    }
+
+    #[test]
+    fn prefers_pss_over_pkcs1_when_both_offered() {
+        let key = RsaPrivateKey::new(&mut rand_core::OsRng, MIN_MODULUS_BITS).unwrap();
+        let der = key.to_pkcs8_der().unwrap();
+        let private = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.as_bytes().to_vec()));
+        let signing_key = RsaSigningKey::try_from(&private).unwrap();
+
+        let offered = [
+            SignatureScheme::RSA_PKCS1_SHA256,
+            SignatureScheme::RSA_PSS_SHA256,
+        ];
+        let signer = signing_key.choose_scheme(&offered).expect("scheme offered");
+        assert_eq!(signer.scheme(), SignatureScheme::RSA_PSS_SHA256);
+    }
+
+    #[test]
+    fn falls_back_to_pkcs1_when_pss_not_offered() {
+        let key = RsaPrivateKey::new(&mut rand_core::OsRng, MIN_MODULUS_BITS).unwrap();
+        let der = key.to_pkcs8_der().unwrap();
+        let private = PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(der.as_bytes().to_vec()));
+        let signing_key = RsaSigningKey::try_from(&private).unwrap();
+
+        let signer = signing_key
+            .choose_scheme(&[SignatureScheme::RSA_PKCS1_SHA384])
+            .expect("scheme offered");
+        assert_eq!(signer.scheme(), SignatureScheme::RSA_PKCS1_SHA384);
+    }
+}